@@ -19,6 +19,153 @@ impl PromiseSubtype {
     }
 }
 
+/// A single action that has been scheduled on a [`Promise`] but not yet dispatched to the host.
+///
+/// Builder methods on `Promise` (`create_account`, `transfer`, `function_call`, etc.) queue one
+/// of these per call instead of immediately invoking the corresponding `env::promise_batch_action_*`
+/// host function. The queue is flushed, in order, once the promise is dropped or serialized as
+/// the return value of the contract call. This makes a `Promise` inspectable via
+/// [`Promise::actions`] without requiring a live VM.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    borsh::BorshSchema,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum PromiseAction {
+    CreateAccount,
+    DeployContract {
+        code: Vec<u8>,
+    },
+    FunctionCall {
+        function_name: String,
+        arguments: Vec<u8>,
+        amount: Balance,
+        gas: Gas,
+    },
+    FunctionCallWeight {
+        function_name: String,
+        arguments: Vec<u8>,
+        amount: Balance,
+        gas: Gas,
+        weight: GasWeight,
+    },
+    Transfer {
+        amount: Balance,
+    },
+    Stake {
+        amount: Balance,
+        public_key: PublicKey,
+    },
+    AddFullAccessKey {
+        public_key: PublicKey,
+        nonce: u64,
+    },
+    AddAccessKey {
+        public_key: PublicKey,
+        allowance: Balance,
+        receiver_id: AccountId,
+        function_names: String,
+        nonce: u64,
+    },
+    DeleteKey {
+        public_key: PublicKey,
+    },
+    DeleteAccount {
+        beneficiary_id: AccountId,
+    },
+}
+
+impl PromiseAction {
+    /// Dispatches this action to the host, against the batch identified by `promise_index`.
+    pub(crate) fn add(&self, promise_index: PromiseIndex) {
+        use PromiseAction::*;
+        match self {
+            CreateAccount => crate::env::promise_batch_action_create_account(promise_index),
+            DeployContract { code } => {
+                crate::env::promise_batch_action_deploy_contract(promise_index, code)
+            }
+            FunctionCall { function_name, arguments, amount, gas } => {
+                crate::env::promise_batch_action_function_call(
+                    promise_index,
+                    function_name,
+                    arguments,
+                    *amount,
+                    *gas,
+                )
+            }
+            FunctionCallWeight { function_name, arguments, amount, gas, weight } => {
+                crate::env::promise_batch_action_function_call_weight(
+                    promise_index,
+                    function_name,
+                    arguments,
+                    *amount,
+                    *gas,
+                    *weight,
+                )
+            }
+            Transfer { amount } => {
+                crate::env::promise_batch_action_transfer(promise_index, *amount)
+            }
+            Stake { amount, public_key } => {
+                crate::env::promise_batch_action_stake(promise_index, *amount, public_key)
+            }
+            AddFullAccessKey { public_key, nonce } => {
+                crate::env::promise_batch_action_add_key_with_full_access(
+                    promise_index,
+                    public_key,
+                    *nonce,
+                )
+            }
+            AddAccessKey { public_key, allowance, receiver_id, function_names, nonce } => {
+                crate::env::promise_batch_action_add_key_with_function_call(
+                    promise_index,
+                    public_key,
+                    *nonce,
+                    *allowance,
+                    receiver_id,
+                    function_names,
+                )
+            }
+            DeleteKey { public_key } => {
+                crate::env::promise_batch_action_delete_key(promise_index, public_key)
+            }
+            DeleteAccount { beneficiary_id } => {
+                crate::env::promise_batch_action_delete_account(promise_index, beneficiary_id)
+            }
+        }
+    }
+}
+
+/// The relative weight of a particular function call action against other function calls
+/// scheduled in the same batch, used to determine how much of the unused prepaid gas is
+/// distributed to it. A weight of `0` means no unused gas is allocated, i.e. the call only
+/// gets the fixed `gas` amount it was scheduled with.
+///
+/// The unused gas is split across all actions with a non-zero weight in proportion to their
+/// weight, e.g. a weight of `2` receives twice as much unused gas as a weight of `1`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    borsh::BorshSchema,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct GasWeight(pub u64);
+
 /// A structure representing a result of the scheduled execution on another contract.
 ///
 /// Smart contract developers will explicitly use `Promise` in two situations:
@@ -61,6 +208,7 @@ impl PromiseSubtype {
 /// ```
 pub struct Promise {
     index: PromiseSubtype,
+    actions: RefCell<Vec<PromiseAction>>,
     should_return: RefCell<bool>,
 }
 
@@ -82,38 +230,47 @@ impl Promise {
     pub fn new(account_id: &AccountId) -> Self {
         Self {
             index: PromiseSubtype::Single(crate::env::promise_batch_create(&account_id)),
+            actions: RefCell::new(Vec::new()),
             should_return: RefCell::new(false),
         }
     }
 
     // TODO this should prob be restricted at compile time
-    fn action_index(&self) -> PromiseIndex {
-        match self.index {
-            PromiseSubtype::Single(x) => x,
-            PromiseSubtype::Joint(_) => crate::env::panic_str("Cannot add action to a joint promise."),
+    fn add_action(self, action: PromiseAction) -> Self {
+        match &self.index {
+            PromiseSubtype::Single(_) => self.actions.borrow_mut().push(action),
+            PromiseSubtype::Joint(_) => {
+                crate::env::panic_str("Cannot add action to a joint promise.")
+            }
         }
+        self
     }
 
-    // fn add_action(self, action: PromiseAction) -> Self {
-    //     match &self.index {
-    //         PromiseTy::Single(x) => x.actions.borrow_mut().push(action),
-    //         PromiseSubtype::Joint(_) => {
-    //             crate::env::panic_str("Cannot add action to a joint promise.")
-    //         }
-    //     }
-    //     self
-    // }
+    /// Returns the actions that have been queued on this promise but not yet dispatched to the
+    /// host. Actions are flushed, in order, when the promise is dropped or serialized as the
+    /// return value of the contract call.
+    pub fn actions(&self) -> std::cell::Ref<'_, Vec<PromiseAction>> {
+        self.actions.borrow()
+    }
+
+    /// Flushes any queued actions to the host, in the order they were added. Idempotent: actions
+    /// are drained as they are flushed, so calling this more than once is a no-op after the
+    /// first call.
+    fn flush_actions(&self) {
+        let promise_index = self.index.index();
+        for action in self.actions.borrow_mut().drain(..) {
+            action.add(promise_index);
+        }
+    }
 
     /// Create account on which this promise acts.
     pub fn create_account(self) -> Self {
-        crate::env::promise_batch_action_create_account(self.action_index());
-        self
+        self.add_action(PromiseAction::CreateAccount)
     }
 
     /// Deploy a smart contract to the account on which this promise acts.
     pub fn deploy_contract(self, code: &[u8]) -> Self {
-        crate::env::promise_batch_action_deploy_contract(self.action_index(), code);
-        self
+        self.add_action(PromiseAction::DeployContract { code: code.to_vec() })
     }
 
     /// A low-level interface for making a function call to the account that this promise acts on.
@@ -124,46 +281,61 @@ impl Promise {
         amount: Balance,
         gas: Gas,
     ) -> Self {
-        crate::env::promise_batch_action_function_call(
-            self.action_index(),
-            function_name,
-            arguments,
+        self.add_action(PromiseAction::FunctionCall {
+            function_name: function_name.to_string(),
+            arguments: arguments.to_vec(),
             amount,
             gas,
-        );
-        self
+        })
+    }
+
+    /// A low-level interface for making a function call to the account that this promise acts
+    /// on, additionally assigning it a share of whatever prepaid gas is left unused once all
+    /// other actions in the batch have been accounted for.
+    ///
+    /// `gas` is still attached as a floor for the call. On top of that, any gas left over after
+    /// fixed allocations is split across all calls scheduled with a non-zero `weight`, in
+    /// proportion to their weight (using integer division; any remainder is assigned to the
+    /// last such call). Passing `GasWeight(0)` is equivalent to [`Promise::function_call`] and
+    /// only ever attaches the fixed `gas` amount.
+    ///
+    /// This is useful when a contract wants to forward "all remaining gas" to a single
+    /// cross-contract call without having to compute the exact figure itself.
+    pub fn function_call_weight(
+        self,
+        function_name: &str,
+        arguments: &[u8],
+        amount: Balance,
+        gas: Gas,
+        weight: GasWeight,
+    ) -> Self {
+        self.add_action(PromiseAction::FunctionCallWeight {
+            function_name: function_name.to_string(),
+            arguments: arguments.to_vec(),
+            amount,
+            gas,
+            weight,
+        })
     }
 
     /// Transfer tokens to the account that this promise acts on.
     pub fn transfer(self, amount: Balance) -> Self {
-        crate::env::promise_batch_action_transfer(self.action_index(), amount);
-        self
+        self.add_action(PromiseAction::Transfer { amount })
     }
 
     /// Stake the account for the given amount of tokens using the given public key.
     pub fn stake(self, amount: Balance, public_key: &PublicKey) -> Self {
-        crate::env::promise_batch_action_stake(self.action_index(), amount, public_key);
-        self
+        self.add_action(PromiseAction::Stake { amount, public_key: public_key.clone() })
     }
 
     /// Add full access key to the given account.
     pub fn add_full_access_key(self, public_key: &PublicKey) -> Self {
-        crate::env::promise_batch_action_add_key_with_full_access(
-            self.action_index(),
-            public_key,
-            0,
-        );
-        self
+        self.add_full_access_key_with_nonce(public_key, 0)
     }
 
     /// Add full access key to the given account with a provided nonce.
     pub fn add_full_access_key_with_nonce(self, public_key: &PublicKey, nonce: u64) -> Self {
-        crate::env::promise_batch_action_add_key_with_full_access(
-            self.action_index(),
-            public_key,
-            nonce,
-        );
-        self
+        self.add_action(PromiseAction::AddFullAccessKey { public_key: public_key.clone(), nonce })
     }
 
     /// Add an access key that is restricted to only calling a smart contract on some account using
@@ -177,15 +349,7 @@ impl Promise {
         // TODO maybe want to change this to slice of &str
         function_names: &str,
     ) -> Self {
-        crate::env::promise_batch_action_add_key_with_function_call(
-            self.action_index(),
-            public_key,
-            0,
-            allowance,
-            receiver_id,
-            function_names,
-        );
-        self
+        self.add_access_key_with_nonce(public_key, allowance, receiver_id, function_names, 0)
     }
 
     /// Add an access key with a provided nonce.
@@ -197,27 +361,23 @@ impl Promise {
         function_names: &str,
         nonce: u64,
     ) -> Self {
-        crate::env::promise_batch_action_add_key_with_function_call(
-            self.action_index(),
-            public_key,
-            nonce,
+        self.add_action(PromiseAction::AddAccessKey {
+            public_key: public_key.clone(),
             allowance,
-            receiver_id,
-            function_names,
-        );
-        self
+            receiver_id: receiver_id.clone(),
+            function_names: function_names.to_string(),
+            nonce,
+        })
     }
 
     /// Delete access key from the given account.
     pub fn delete_key(self, public_key: &PublicKey) -> Self {
-        crate::env::promise_batch_action_delete_key(self.action_index(), public_key);
-        self
+        self.add_action(PromiseAction::DeleteKey { public_key: public_key.clone() })
     }
 
     /// Delete the given account.
     pub fn delete_account(self, beneficiary_id: &AccountId) -> Self {
-        crate::env::promise_batch_action_delete_account(self.action_index(), beneficiary_id);
-        self
+        self.add_action(PromiseAction::DeleteAccount { beneficiary_id: beneficiary_id.clone() })
     }
 
     /// Merge this promise with another promise, so that we can schedule execution of another
@@ -235,16 +395,53 @@ impl Promise {
     /// ```
     pub fn and(self, other: Promise) -> Promise {
         Self {
-            // TODO current impl seems to call unnecessary `promise_and`. Yes, this might be
-            // TODO functional, but more optimal if `and` all at once.
+            // Merging promises pairwise like this issues one `promise_and` host call per merge.
+            // For joining more than two promises at once, prefer `Promise::join_all`/`and_many`,
+            // which issue a single `promise_and` call over all of them.
             index: PromiseSubtype::Joint(crate::env::promise_and(&[
                 self.index.index(),
                 other.index.index(),
             ])),
+            actions: RefCell::new(Vec::new()),
             should_return: RefCell::new(false),
         }
     }
 
+    /// Joins any number of promises together into one, issuing a single `promise_and` host call
+    /// over all of their indices at once.
+    ///
+    /// This avoids the redundant `promise_and` calls that result from merging promises pairwise
+    /// with [`Promise::and`], and gives a clearer API for fanning out to many receivers before a
+    /// common `then` callback.
+    ///
+    /// ```no_run
+    /// # use near_sdk::Promise;
+    /// let p1 = Promise::new("bob_near".parse().unwrap()).create_account();
+    /// let p2 = Promise::new("carol_near".parse().unwrap()).create_account();
+    /// let p3 = Promise::new("dave_near".parse().unwrap()).create_account();
+    /// Promise::join_all(vec![p1, p2, p3]);
+    /// ```
+    pub fn join_all(promises: Vec<Promise>) -> Promise {
+        if promises.is_empty() {
+            crate::env::panic_str("Must join at least one promise");
+        }
+        let indices: Vec<PromiseIndex> = promises.iter().map(|p| p.index.index()).collect();
+        Self {
+            index: PromiseSubtype::Joint(crate::env::promise_and(&indices)),
+            actions: RefCell::new(Vec::new()),
+            should_return: RefCell::new(false),
+        }
+    }
+
+    /// Merges this promise with any number of other promises in a single `promise_and` host
+    /// call. Equivalent to `Promise::join_all` with `self` prepended to `others`.
+    pub fn and_many(self, others: Vec<Promise>) -> Promise {
+        let mut promises = Vec::with_capacity(others.len() + 1);
+        promises.push(self);
+        promises.extend(others);
+        Self::join_all(promises)
+    }
+
     /// Schedules execution of another promise right after the current promise finish executing.
     ///
     /// In the following code `bob_near` and `dave_near` will be created concurrently. `carol_near`
@@ -261,10 +458,44 @@ impl Promise {
     pub fn then(self, other: &AccountId) -> Promise {
         Self {
             index: PromiseSubtype::Single(crate::env::promise_batch_then(self.index.index(), other)),
+            actions: RefCell::new(Vec::new()),
             should_return: RefCell::new(false),
         }
     }
 
+    /// Schedules `next` to execute only once `self` has fully finished, rewriting what would
+    /// otherwise be two concurrently-running promises into a strict two-step dependency chain.
+    ///
+    /// `next` is a plain [`ActionBatch`] rather than a live `Promise` precisely so that its host
+    /// promise is not created until it is actually chained: a `Promise` allocates its batch (via
+    /// `promise_batch_create`/`promise_batch_then`) as soon as it is built, so accepting an
+    /// already-live `Promise` here would leave that earlier batch dangling -- never merged,
+    /// chained, or returned -- while still being dispatched to the host as a spurious, empty
+    /// receipt. Building the dependent batch here, on top of `self`, guarantees there is exactly
+    /// one receipt per step in the chain.
+    ///
+    /// This is useful for ordering hazards where one action (e.g. a token transfer/bridge that
+    /// funds an account) must complete before a later cross-contract call that depends on it --
+    /// without the caller having to manually thread callback receipts to enforce the ordering.
+    pub fn then_sequential(self, next: ActionBatch) -> Promise {
+        let chained = self.then(&next.receiver_id);
+        *chained.actions.borrow_mut() = next.actions;
+        chained
+    }
+
+    /// Rewrites a list of action batches that would otherwise run concurrently into a single
+    /// linear dependency chain, executing strictly in the order given, via repeated
+    /// `then_sequential`. Only the first batch's promise is materialized up front; each
+    /// subsequent one is created only once it is actually chained onto the previous step.
+    pub fn chain(batches: Vec<ActionBatch>) -> Promise {
+        let mut batches = batches.into_iter();
+        let first = batches
+            .next()
+            .unwrap_or_else(|| crate::env::panic_str("Must chain at least one promise"));
+        let first = first.into_promise();
+        batches.fold(first, |acc, next| acc.then_sequential(next))
+    }
+
     /// A specialized, relatively low-level API method. Allows to mark the given promise as the one
     /// that should be considered as a return value.
     ///
@@ -299,11 +530,21 @@ impl Promise {
     }
 }
 
+/// Flushes any actions that are still queued once the last handle to the promise goes out of
+/// scope. This is what lets builder methods queue actions instead of dispatching them to the
+/// host immediately, while still guaranteeing they are applied exactly once.
+impl Drop for Promise {
+    fn drop(&mut self) {
+        self.flush_actions();
+    }
+}
+
 impl serde::Serialize for Promise {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        self.flush_actions();
         *self.should_return.borrow_mut() = true;
         serializer.serialize_unit()
     }
@@ -311,6 +552,7 @@ impl serde::Serialize for Promise {
 
 impl borsh::BorshSerialize for Promise {
     fn serialize<W: Write>(&self, _writer: &mut W) -> Result<(), Error> {
+        self.flush_actions();
         *self.should_return.borrow_mut() = true;
 
         // Intentionally no bytes written for the promise, the return value from the promise
@@ -319,6 +561,51 @@ impl borsh::BorshSerialize for Promise {
     }
 }
 
+/// A proposed batch of promise actions targeting a single receiver, suitable for persisting in
+/// contract state before it is dispatched as a live `Promise`.
+///
+/// Unlike `Promise`, which eagerly creates a promise index on the host and cannot be serialized,
+/// `ActionBatch` is plain data -- a `receiver_id` and a list of [`PromiseAction`]s. This makes it
+/// possible to build multisig/governance-style workflows: one call records a proposed batch
+/// (e.g. a transfer, an added key, a deployment) keyed by a request id, collaborators approve it
+/// over several subsequent transactions, and a final call turns the stored batch into an actual
+/// promise via [`ActionBatch::into_promise`].
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    borsh::BorshSerialize,
+    borsh::BorshDeserialize,
+    borsh::BorshSchema,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ActionBatch {
+    pub receiver_id: AccountId,
+    pub actions: Vec<PromiseAction>,
+}
+
+impl ActionBatch {
+    /// Creates an empty batch of actions targeting `receiver_id`.
+    pub fn new(receiver_id: AccountId) -> Self {
+        Self { receiver_id, actions: Vec::new() }
+    }
+
+    /// Appends an action to the batch.
+    pub fn add_action(&mut self, action: PromiseAction) -> &mut Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Converts this batch into a live `Promise`, scheduling all of its actions, in order,
+    /// against a fresh promise targeting `receiver_id`.
+    pub fn into_promise(self) -> Promise {
+        let promise = Promise::new(&self.receiver_id);
+        *promise.actions.borrow_mut() = self.actions;
+        promise
+    }
+}
+
 #[derive(serde::Serialize)]
 #[serde(untagged)]
 pub enum PromiseOrValue<T> {
@@ -357,3 +644,64 @@ impl<T: borsh::BorshSerialize> borsh::BorshSerialize for PromiseOrValue<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::VMContextBuilder;
+    use crate::testing_env;
+
+    fn batch() -> ActionBatch {
+        let mut batch = ActionBatch::new("alice.near".parse().unwrap());
+        batch.add_action(PromiseAction::CreateAccount);
+        batch.add_action(PromiseAction::Transfer { amount: 100 });
+        batch.add_action(PromiseAction::DeleteAccount {
+            beneficiary_id: "bob.near".parse().unwrap(),
+        });
+        batch
+    }
+
+    #[test]
+    fn action_batch_round_trips_through_borsh() {
+        let batch = batch();
+        let bytes = borsh::BorshSerialize::try_to_vec(&batch).unwrap();
+        let decoded: ActionBatch = borsh::BorshDeserialize::try_from_slice(&bytes).unwrap();
+        assert_eq!(batch, decoded);
+    }
+
+    #[test]
+    fn action_batch_round_trips_through_json() {
+        let batch = batch();
+        let json = serde_json::to_string(&batch).unwrap();
+        let decoded: ActionBatch = serde_json::from_str(&json).unwrap();
+        assert_eq!(batch, decoded);
+    }
+
+    #[test]
+    fn add_action_preserves_order() {
+        let batch = batch();
+        assert_eq!(
+            batch.actions,
+            vec![
+                PromiseAction::CreateAccount,
+                PromiseAction::Transfer { amount: 100 },
+                PromiseAction::DeleteAccount { beneficiary_id: "bob.near".parse().unwrap() },
+            ]
+        );
+    }
+
+    #[test]
+    fn into_promise_queues_actions_in_order() {
+        testing_env!(VMContextBuilder::new().build());
+
+        let promise = batch().into_promise();
+        assert_eq!(
+            promise.actions().clone(),
+            vec![
+                PromiseAction::CreateAccount,
+                PromiseAction::Transfer { amount: 100 },
+                PromiseAction::DeleteAccount { beneficiary_id: "bob.near".parse().unwrap() },
+            ]
+        );
+    }
+}